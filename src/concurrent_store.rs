@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::RwLock;
+
+/// Number of independent shards the keyspace is split across.
+///
+/// Each shard is guarded by its own `RwLock`, so keys that hash into
+/// different shards can be read and written in parallel without contending
+/// on a single global lock.
+const SHARD_COUNT: usize = 16;
+
+/// A thread-safe, sharded key-value store.
+///
+/// This is the safe counterpart to the crate's `unsafe_concurrent_get_and_delete`
+/// test, which dramatizes what goes wrong when a plain [`KeyValueStore`](crate::KeyValueStore)
+/// is shared across threads without synchronization. Here the keyspace is
+/// partitioned across [`SHARD_COUNT`] buckets by `hash(key) % SHARD_COUNT`,
+/// each behind its own `RwLock<HashMap<String, String>>`, so keys in
+/// different shards never block each other; keys that hash into the same
+/// shard share that shard's lock.
+pub struct ConcurrentKeyValueStore {
+    shards: Vec<RwLock<HashMap<String, String>>>,
+}
+
+impl ConcurrentKeyValueStore {
+    /// Creates a new, empty concurrent store.
+    pub fn new() -> Self {
+        let shards = (0..SHARD_COUNT).map(|_| RwLock::new(HashMap::new())).collect();
+        ConcurrentKeyValueStore { shards }
+    }
+
+    fn shard_for(&self, key: &str) -> &RwLock<HashMap<String, String>> {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        let index = (hasher.finish() as usize) % self.shards.len();
+        &self.shards[index]
+    }
+
+    /// Retrieves a clone of the value stored at `key`, so no lock guard
+    /// ever escapes this call.
+    pub fn get(&self, key: &str) -> Option<String> {
+        self.shard_for(key).read().unwrap().get(key).cloned()
+    }
+
+    /// Inserts or updates a key-value pair.
+    pub fn set(&self, key: String, value: String) {
+        let shard = self.shard_for(&key);
+        shard.write().unwrap().insert(key, value);
+    }
+
+    /// Removes a key-value pair, returning the owned value if it existed.
+    pub fn delete(&self, key: &str) -> Option<String> {
+        self.shard_for(key).write().unwrap().remove(key)
+    }
+
+    /// Atomically reads and replaces the value at `key` in a single
+    /// critical section, avoiding the race a separate `get` then `set`
+    /// would have under concurrent access.
+    ///
+    /// `f` receives the current value (if any) and returns the new value to
+    /// store, or `None` to delete the key.
+    pub fn update(&self, key: &str, f: impl FnOnce(Option<&str>) -> Option<String>) {
+        let mut guard = self.shard_for(key).write().unwrap();
+        let current = guard.get(key).map(String::as_str);
+        match f(current) {
+            Some(new_value) => {
+                guard.insert(key.to_string(), new_value);
+            }
+            None => {
+                guard.remove(key);
+            }
+        }
+    }
+
+    /// Returns the total number of key-value pairs across all shards.
+    pub fn len(&self) -> usize {
+        self.shards.iter().map(|s| s.read().unwrap().len()).sum()
+    }
+
+    /// Returns `true` if the store contains no key-value pairs.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Default for ConcurrentKeyValueStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::thread;
+
+    #[test]
+    fn set_get_and_delete() {
+        let store = ConcurrentKeyValueStore::new();
+        store.set("name".to_string(), "Alice".to_string());
+
+        assert_eq!(store.get("name"), Some("Alice".to_string()));
+        assert_eq!(store.delete("name"), Some("Alice".to_string()));
+        assert_eq!(store.get("name"), None);
+    }
+
+    #[test]
+    fn update_is_an_atomic_read_modify_write() {
+        let store = ConcurrentKeyValueStore::new();
+        store.update("counter", |current| {
+            let n: i64 = current.unwrap_or("0").parse().unwrap();
+            Some((n + 1).to_string())
+        });
+        store.update("counter", |current| {
+            let n: i64 = current.unwrap_or("0").parse().unwrap();
+            Some((n + 1).to_string())
+        });
+
+        assert_eq!(store.get("counter"), Some("2".to_string()));
+    }
+
+    #[test]
+    fn update_with_none_deletes_the_key() {
+        let store = ConcurrentKeyValueStore::new();
+        store.set("k".to_string(), "v".to_string());
+        store.update("k", |_| None);
+
+        assert_eq!(store.get("k"), None);
+    }
+
+    #[test]
+    fn concurrent_writes_to_distinct_keys_all_land() {
+        let store = Arc::new(ConcurrentKeyValueStore::new());
+        let mut handles = Vec::new();
+
+        for i in 0..50 {
+            let store = Arc::clone(&store);
+            handles.push(thread::spawn(move || {
+                store.set(format!("key{}", i), format!("value{}", i));
+            }));
+        }
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert_eq!(store.len(), 50);
+        for i in 0..50 {
+            assert_eq!(store.get(&format!("key{}", i)), Some(format!("value{}", i)));
+        }
+    }
+}
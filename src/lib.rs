@@ -8,7 +8,15 @@
 //! - `GET`: Retrieve a value by key
 //! - `SET`: Insert or update a key-value pair
 //! - `DELETE`: Remove a key-value pair
-//! 
+//! - Ordered iteration and range scans over keys (`iter`, `iter_from`, `range`)
+//! - Bulk removal via `retain` and `drain_filter`
+//! - Disk persistence via `save_to`/`load_from` (and `_path` convenience wrappers)
+//! - `entry`-style atomic read-modify-write access
+//! - [`TransactionalStore`]: snapshot-isolated reads and buffered, atomic writes
+//! - [`TypedStore`]: heterogeneous values (strings, ints, floats, bools, blobs)
+//! - [`MultiKeyValueStore`]: multiple ordered values per key
+//! - [`ConcurrentKeyValueStore`]: sharded, `Send + Sync` store for safe cross-thread access
+//!
 //! ## Example
 //! 
 //! ```
@@ -29,6 +37,14 @@
 //! assert_eq!(old_name, Some("Alice".to_string()));
 //! ```
 
+mod concurrent_store;
+mod multi_store;
 mod store;
+mod transaction;
+mod typed_store;
 
-pub use store::KeyValueStore;
+pub use concurrent_store::ConcurrentKeyValueStore;
+pub use multi_store::MultiKeyValueStore;
+pub use store::{Entry, KeyValueStore};
+pub use transaction::{Reader, TransactionalStore, Writer};
+pub use typed_store::{FromValue, TypedStore, Value};
@@ -0,0 +1,120 @@
+use std::collections::{BTreeSet, HashMap};
+
+/// A key-value store where each key maps to an ordered set of values rather
+/// than a single value, modeled on rkv's `MultiStore`.
+///
+/// # Ownership Notes
+/// - `put` takes ownership of both the key and the value
+/// - `get_all` borrows the values associated with a key
+/// - `delete_value` only needs to borrow the key and value to find the pair
+#[derive(Debug, Default)]
+pub struct MultiKeyValueStore {
+    data: HashMap<String, BTreeSet<String>>,
+}
+
+impl MultiKeyValueStore {
+    /// Creates a new, empty multi-value store.
+    pub fn new() -> Self {
+        MultiKeyValueStore {
+            data: HashMap::new(),
+        }
+    }
+
+    /// Associates `value` with `key`, appending it to the key's set of
+    /// values rather than overwriting any existing ones.
+    ///
+    /// Values for a given key are deduplicated and kept in sorted order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use in_memory_db::MultiKeyValueStore;
+    ///
+    /// let mut store = MultiKeyValueStore::new();
+    /// store.put("tags".to_string(), "rust".to_string());
+    /// store.put("tags".to_string(), "database".to_string());
+    ///
+    /// let values: Vec<&String> = store.get_all("tags").collect();
+    /// assert_eq!(values, vec!["database", "rust"]);
+    /// ```
+    pub fn put(&mut self, key: String, value: String) {
+        self.data.entry(key).or_default().insert(value);
+    }
+
+    /// Returns an iterator over all values associated with `key`, in sorted
+    /// order. Yields nothing if the key is absent.
+    pub fn get_all(&self, key: &str) -> impl Iterator<Item = &String> {
+        self.data.get(key).into_iter().flatten()
+    }
+
+    /// Removes one specific `(key, value)` pair, leaving any other values
+    /// for that key untouched.
+    ///
+    /// Returns `true` if the pair was present and removed. If it was the
+    /// last value for `key`, the key itself is dropped from the store.
+    pub fn delete_value(&mut self, key: &str, value: &str) -> bool {
+        let Some(values) = self.data.get_mut(key) else {
+            return false;
+        };
+        let removed = values.remove(value);
+        if values.is_empty() {
+            self.data.remove(key);
+        }
+        removed
+    }
+
+    /// Returns the number of distinct keys in the store.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if the store contains no keys.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn put_appends_and_dedupes_values() {
+        let mut store = MultiKeyValueStore::new();
+        store.put("tags".to_string(), "rust".to_string());
+        store.put("tags".to_string(), "rust".to_string());
+        store.put("tags".to_string(), "database".to_string());
+
+        let values: Vec<&String> = store.get_all("tags").collect();
+        assert_eq!(values, vec!["database", "rust"]);
+    }
+
+    #[test]
+    fn get_all_on_missing_key_yields_nothing() {
+        let store = MultiKeyValueStore::new();
+        assert_eq!(store.get_all("missing").count(), 0);
+    }
+
+    #[test]
+    fn delete_value_removes_only_the_matching_pair() {
+        let mut store = MultiKeyValueStore::new();
+        store.put("tags".to_string(), "rust".to_string());
+        store.put("tags".to_string(), "database".to_string());
+
+        assert!(store.delete_value("tags", "rust"));
+        assert!(!store.delete_value("tags", "rust")); // already gone
+
+        let values: Vec<&String> = store.get_all("tags").collect();
+        assert_eq!(values, vec!["database"]);
+    }
+
+    #[test]
+    fn delete_value_drops_key_once_its_last_value_is_removed() {
+        let mut store = MultiKeyValueStore::new();
+        store.put("tags".to_string(), "rust".to_string());
+
+        assert!(store.delete_value("tags", "rust"));
+        assert!(store.is_empty());
+        assert_eq!(store.get_all("tags").count(), 0);
+    }
+}
@@ -1,14 +1,28 @@
-use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use std::collections::{btree_map, BTreeMap};
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::ops::Bound;
+use std::path::Path;
 
 /// An in-memory key-value store demonstrating Rust's ownership and move semantics.
-/// 
+///
 /// This implementation uses String keys and values to highlight ownership patterns:
 /// - Keys and values are owned by the store
 /// - Methods carefully balance ownership vs borrowing
 /// - The borrow checker prevents common bugs at compile time
-#[derive(Debug)]
+///
+/// Keys are kept in a `BTreeMap` rather than a `HashMap`, so in addition to
+/// point lookups the store supports ordered traversal via [`iter`](KeyValueStore::iter),
+/// [`iter_from`](KeyValueStore::iter_from), and [`range`](KeyValueStore::range).
+///
+/// The store derives `Serialize`/`Deserialize` (following the pattern
+/// hashbrown's `HashMap` uses for its own serde support), so a snapshot can
+/// be written to and read back from disk with [`save_to`](KeyValueStore::save_to)
+/// and [`load_from`](KeyValueStore::load_from).
+#[derive(Debug, Serialize, Deserialize)]
 pub struct KeyValueStore {
-    data: HashMap<String, String>,
+    data: BTreeMap<String, String>,
 }
 
 impl KeyValueStore {
@@ -23,7 +37,7 @@ impl KeyValueStore {
     /// ```
     pub fn new() -> Self {
         KeyValueStore {
-            data: HashMap::new(),
+            data: BTreeMap::new(),
         }
     }
 
@@ -130,6 +144,231 @@ impl KeyValueStore {
     pub fn is_empty(&self) -> bool {
         self.data.is_empty()
     }
+
+    /// Iterates over all entries in ascending key order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use in_memory_db::KeyValueStore;
+    ///
+    /// let mut store = KeyValueStore::new();
+    /// store.set("b".to_string(), "2".to_string());
+    /// store.set("a".to_string(), "1".to_string());
+    ///
+    /// let keys: Vec<&str> = store.iter().map(|(k, _)| k).collect();
+    /// assert_eq!(keys, vec!["a", "b"]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &String)> {
+        self.data.iter().map(|(k, v)| (k.as_str(), v))
+    }
+
+    /// Iterates in ascending key order starting at the first key
+    /// lexicographically greater than or equal to `start`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use in_memory_db::KeyValueStore;
+    ///
+    /// let mut store = KeyValueStore::new();
+    /// store.set("user:1".to_string(), "Alice".to_string());
+    /// store.set("user:2".to_string(), "Bob".to_string());
+    /// store.set("zzz".to_string(), "last".to_string());
+    ///
+    /// let keys: Vec<&str> = store.iter_from("user:").map(|(k, _)| k).collect();
+    /// assert_eq!(keys, vec!["user:1", "user:2", "zzz"]);
+    /// ```
+    pub fn iter_from(&self, start: &str) -> impl Iterator<Item = (&str, &String)> {
+        self.data
+            .range::<str, _>((Bound::Included(start), Bound::Unbounded))
+            .map(|(k, v)| (k.as_str(), v))
+    }
+
+    /// Iterates over the bounded range of keys `start..end` (inclusive of
+    /// `start`, exclusive of `end`), in ascending key order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use in_memory_db::KeyValueStore;
+    ///
+    /// let mut store = KeyValueStore::new();
+    /// store.set("a".to_string(), "1".to_string());
+    /// store.set("b".to_string(), "2".to_string());
+    /// store.set("c".to_string(), "3".to_string());
+    ///
+    /// let keys: Vec<&str> = store.range("a", "c").map(|(k, _)| k).collect();
+    /// assert_eq!(keys, vec!["a", "b"]);
+    /// ```
+    pub fn range(&self, start: &str, end: &str) -> impl Iterator<Item = (&str, &String)> {
+        self.data
+            .range::<str, _>((Bound::Included(start), Bound::Excluded(end)))
+            .map(|(k, v)| (k.as_str(), v))
+    }
+
+    /// Keeps only the entries for which `f` returns `true`, removing the
+    /// rest in place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use in_memory_db::KeyValueStore;
+    ///
+    /// let mut store = KeyValueStore::new();
+    /// store.set("a".to_string(), "keep".to_string());
+    /// store.set("b".to_string(), "drop".to_string());
+    ///
+    /// store.retain(|_, v| v == "keep");
+    /// assert_eq!(store.len(), 1);
+    /// assert_eq!(store.get("a"), Some(&"keep".to_string()));
+    /// ```
+    pub fn retain(&mut self, mut f: impl FnMut(&str, &str) -> bool) {
+        self.data.retain(|k, v| f(k, v));
+    }
+
+    /// Removes every entry for which `f` returns `true` and returns the
+    /// removed pairs, so the caller takes ownership of the extracted data.
+    ///
+    /// This is the owned-value counterpart to [`delete`](KeyValueStore::delete)
+    /// for bulk removal, e.g. purging all entries with a given value prefix
+    /// with a single call. Internally it first collects the matching keys,
+    /// then removes them one at a time; pairs are returned in ascending key order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use in_memory_db::KeyValueStore;
+    ///
+    /// let mut store = KeyValueStore::new();
+    /// store.set("a".to_string(), "stale:1".to_string());
+    /// store.set("b".to_string(), "fresh".to_string());
+    ///
+    /// let removed = store.drain_filter(|_, v| v.starts_with("stale:"));
+    /// assert_eq!(removed, vec![("a".to_string(), "stale:1".to_string())]);
+    /// assert_eq!(store.len(), 1);
+    /// ```
+    pub fn drain_filter(&mut self, mut f: impl FnMut(&str, &str) -> bool) -> Vec<(String, String)> {
+        let matching_keys: Vec<String> = self
+            .data
+            .iter()
+            .filter(|(k, v)| f(k, v))
+            .map(|(k, _)| k.clone())
+            .collect();
+
+        matching_keys
+            .into_iter()
+            .map(|k| {
+                let v = self.data.remove(&k).expect("key was just observed in data");
+                (k, v)
+            })
+            .collect()
+    }
+
+    /// Writes a snapshot of the store to `w` as compact JSON.
+    ///
+    /// Use the `bincode` feature to write the more compact bincode format
+    /// instead; [`save_to`](KeyValueStore::save_to) picks the format at
+    /// compile time so callers never need to choose explicitly.
+    pub fn save_to<W: Write>(&self, mut w: W) -> io::Result<()> {
+        #[cfg(feature = "bincode")]
+        {
+            bincode::serialize_into(&mut w, self).map_err(io::Error::other)
+        }
+        #[cfg(not(feature = "bincode"))]
+        {
+            serde_json::to_writer(&mut w, self).map_err(io::Error::from)
+        }
+    }
+
+    /// Reads back a snapshot previously written by [`save_to`](KeyValueStore::save_to),
+    /// taking ownership of all deserialized keys and values.
+    pub fn load_from<R: Read>(r: R) -> io::Result<Self> {
+        #[cfg(feature = "bincode")]
+        {
+            bincode::deserialize_from(r).map_err(io::Error::other)
+        }
+        #[cfg(not(feature = "bincode"))]
+        {
+            serde_json::from_reader(r).map_err(io::Error::from)
+        }
+    }
+
+    /// Convenience wrapper around [`save_to`](KeyValueStore::save_to) that
+    /// writes directly to a file at `path`, creating or truncating it.
+    pub fn save_to_path<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        self.save_to(BufWriter::new(File::create(path)?))
+    }
+
+    /// Convenience wrapper around [`load_from`](KeyValueStore::load_from)
+    /// that reads directly from a file at `path`.
+    pub fn load_from_path<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::load_from(BufReader::new(File::open(path)?))
+    }
+
+    /// Returns an [`Entry`] for `key`, allowing a lookup and an insert or
+    /// update to share a single traversal of the map instead of a separate
+    /// `get` followed by `set`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use in_memory_db::KeyValueStore;
+    ///
+    /// let mut store = KeyValueStore::new();
+    /// store.entry("count".to_string()).or_insert("0".to_string());
+    /// store
+    ///     .entry("count".to_string())
+    ///     .and_modify(|v| *v = "1".to_string())
+    ///     .or_insert("0".to_string());
+    ///
+    /// assert_eq!(store.get("count"), Some(&"1".to_string()));
+    /// ```
+    pub fn entry(&mut self, key: String) -> Entry<'_> {
+        Entry {
+            inner: self.data.entry(key),
+        }
+    }
+
+    /// Convenience wrapper around [`entry`](KeyValueStore::entry) that
+    /// returns the existing value for `key`, inserting `default` first if
+    /// it wasn't already present. `key` is taken by value on every call;
+    /// it is stored only if an insert happens and dropped otherwise.
+    pub fn get_or_insert(&mut self, key: String, default: String) -> &mut String {
+        self.entry(key).or_insert(default)
+    }
+}
+
+/// A view into a single entry of a [`KeyValueStore`], obtained from
+/// [`KeyValueStore::entry`].
+///
+/// Modeled on the standard library's `HashMap`/`BTreeMap` `Entry` API, so
+/// callers can increment counters or append to values with a single map
+/// lookup rather than a `get` followed by a `set`.
+pub struct Entry<'a> {
+    inner: btree_map::Entry<'a, String, String>,
+}
+
+impl<'a> Entry<'a> {
+    /// Inserts `default` if the key is absent, keeping any existing value,
+    /// and returns a mutable reference to the value in the entry.
+    pub fn or_insert(self, default: String) -> &'a mut String {
+        self.inner.or_insert(default)
+    }
+
+    /// Like [`or_insert`](Entry::or_insert), but only runs `default` if the
+    /// key is actually absent.
+    pub fn or_insert_with(self, default: impl FnOnce() -> String) -> &'a mut String {
+        self.inner.or_insert_with(default)
+    }
+
+    /// Runs `f` against the existing value if the key is present, leaving
+    /// the entry vacant if it isn't.
+    pub fn and_modify(self, f: impl FnOnce(&mut String)) -> Self {
+        Entry {
+            inner: self.inner.and_modify(f),
+        }
+    }
 }
 
 impl Default for KeyValueStore {
@@ -192,6 +431,149 @@ mod tests {
         assert_eq!(deleted, None);
     }
 
+    #[test]
+    fn test_iter_is_sorted_by_key() {
+        let mut store = KeyValueStore::new();
+        store.set("charlie".to_string(), "3".to_string());
+        store.set("alice".to_string(), "1".to_string());
+        store.set("bob".to_string(), "2".to_string());
+
+        let keys: Vec<&str> = store.iter().map(|(k, _)| k).collect();
+        assert_eq!(keys, vec!["alice", "bob", "charlie"]);
+    }
+
+    #[test]
+    fn test_iter_from_seeks_to_first_key_geq_start() {
+        let mut store = KeyValueStore::new();
+        store.set("user:1".to_string(), "a".to_string());
+        store.set("user:2".to_string(), "b".to_string());
+        store.set("admin:1".to_string(), "c".to_string());
+
+        let keys: Vec<&str> = store.iter_from("user:").map(|(k, _)| k).collect();
+        assert_eq!(keys, vec!["user:1", "user:2"]);
+    }
+
+    #[test]
+    fn test_range_is_half_open() {
+        let mut store = KeyValueStore::new();
+        store.set("a".to_string(), "1".to_string());
+        store.set("b".to_string(), "2".to_string());
+        store.set("c".to_string(), "3".to_string());
+
+        let keys: Vec<&str> = store.range("a", "c").map(|(k, _)| k).collect();
+        assert_eq!(keys, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_retain_keeps_only_matching_entries() {
+        let mut store = KeyValueStore::new();
+        store.set("a".to_string(), "keep".to_string());
+        store.set("b".to_string(), "drop".to_string());
+        store.set("c".to_string(), "keep".to_string());
+
+        store.retain(|_, v| v == "keep");
+
+        assert_eq!(store.len(), 2);
+        assert_eq!(store.get("a"), Some(&"keep".to_string()));
+        assert_eq!(store.get("b"), None);
+        assert_eq!(store.get("c"), Some(&"keep".to_string()));
+    }
+
+    #[test]
+    fn test_drain_filter_removes_and_returns_matching_pairs() {
+        let mut store = KeyValueStore::new();
+        store.set("a".to_string(), "stale:1".to_string());
+        store.set("b".to_string(), "fresh".to_string());
+        store.set("c".to_string(), "stale:2".to_string());
+
+        let mut removed = store.drain_filter(|_, v| v.starts_with("stale:"));
+        removed.sort();
+
+        assert_eq!(
+            removed,
+            vec![
+                ("a".to_string(), "stale:1".to_string()),
+                ("c".to_string(), "stale:2".to_string()),
+            ]
+        );
+        assert_eq!(store.len(), 1);
+        assert_eq!(store.get("b"), Some(&"fresh".to_string()));
+    }
+
+    #[test]
+    fn test_save_to_and_load_from_round_trip() {
+        let mut store = KeyValueStore::new();
+        store.set("name".to_string(), "Alice".to_string());
+        store.set("city".to_string(), "Seattle".to_string());
+
+        let mut buffer = Vec::new();
+        store.save_to(&mut buffer).unwrap();
+
+        let loaded = KeyValueStore::load_from(buffer.as_slice()).unwrap();
+        assert_eq!(loaded.len(), 2);
+        assert_eq!(loaded.get("name"), Some(&"Alice".to_string()));
+        assert_eq!(loaded.get("city"), Some(&"Seattle".to_string()));
+    }
+
+    #[test]
+    fn test_save_to_path_and_load_from_path_round_trip() {
+        let mut store = KeyValueStore::new();
+        store.set("key".to_string(), "value".to_string());
+
+        let path = std::env::temp_dir().join(format!(
+            "in_memory_db_test_{}.json",
+            std::process::id()
+        ));
+        store.save_to_path(&path).unwrap();
+
+        let loaded = KeyValueStore::load_from_path(&path).unwrap();
+        assert_eq!(loaded.get("key"), Some(&"value".to_string()));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_entry_or_insert_inserts_when_absent() {
+        let mut store = KeyValueStore::new();
+        let value = store.entry("count".to_string()).or_insert("0".to_string());
+        assert_eq!(value, "0");
+        assert_eq!(store.get("count"), Some(&"0".to_string()));
+    }
+
+    #[test]
+    fn test_entry_or_insert_keeps_existing_value() {
+        let mut store = KeyValueStore::new();
+        store.set("name".to_string(), "Alice".to_string());
+
+        let value = store.entry("name".to_string()).or_insert("Bob".to_string());
+        assert_eq!(value, "Alice");
+    }
+
+    #[test]
+    fn test_entry_and_modify_only_runs_when_present() {
+        let mut store = KeyValueStore::new();
+
+        store
+            .entry("counter".to_string())
+            .and_modify(|v| *v = "updated".to_string())
+            .or_insert("initial".to_string());
+        assert_eq!(store.get("counter"), Some(&"initial".to_string()));
+
+        store
+            .entry("counter".to_string())
+            .and_modify(|v| *v = "updated".to_string())
+            .or_insert("initial".to_string());
+        assert_eq!(store.get("counter"), Some(&"updated".to_string()));
+    }
+
+    #[test]
+    fn test_get_or_insert() {
+        let mut store = KeyValueStore::new();
+        let value = store.get_or_insert("key".to_string(), "value".to_string());
+        assert_eq!(value, "value");
+        assert_eq!(store.len(), 1);
+    }
+
     #[test]
     fn test_multiple_operations() {
         let mut store = KeyValueStore::new();
@@ -274,7 +656,7 @@ mod tests {
         t2.join().unwrap();
 
         // Reconstruct the Box to properly drop the store and avoid leaking.
-        unsafe { Box::from_raw(ptr); }
+        unsafe { drop(Box::from_raw(ptr)); }
 
         // The value may or may not be "v" depending on timing and UB; this
         // assert is present to show what a successful (non-crashing) run
@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+/// A transactional key-value store with snapshot isolation, modeled on
+/// rkv's `Reader`/`Writer` environment split.
+///
+/// Committed state lives behind a version counter as an `Arc<HashMap>`.
+/// Each commit builds a brand new map (copy-on-write) and swaps it in, so a
+/// [`Reader`] created before a commit keeps its own frozen `Arc` clone and
+/// is never invalidated by writers that race ahead of it. This replaces the
+/// use-after-free hazard that [`KeyValueStore`](crate::KeyValueStore)'s bare
+/// `get`/`delete` pair can hit under concurrent access.
+pub struct TransactionalStore {
+    inner: RwLock<Snapshot>,
+}
+
+struct Snapshot {
+    version: u64,
+    data: Arc<HashMap<String, String>>,
+}
+
+impl TransactionalStore {
+    /// Creates a new, empty transactional store at version `0`.
+    pub fn new() -> Self {
+        TransactionalStore {
+            inner: RwLock::new(Snapshot {
+                version: 0,
+                data: Arc::new(HashMap::new()),
+            }),
+        }
+    }
+
+    /// Opens a read-only transaction.
+    ///
+    /// The returned [`Reader`] captures the current version and an `Arc`
+    /// clone of the committed map; later writes never mutate this snapshot
+    /// in place, so the reader stays consistent for as long as it lives.
+    pub fn begin_read(&self) -> Reader {
+        let snapshot = self.inner.read().unwrap();
+        Reader {
+            version: snapshot.version,
+            data: Arc::clone(&snapshot.data),
+        }
+    }
+
+    /// Opens a read-write transaction.
+    ///
+    /// The returned [`Writer`] buffers `set`/`delete` calls in a local
+    /// overlay and only applies them to the store when [`Writer::commit`]
+    /// is called. A `Writer` dropped without committing discards its
+    /// overlay and leaves the store untouched.
+    pub fn begin_write(&self) -> Writer<'_> {
+        let base = Arc::clone(&self.inner.read().unwrap().data);
+        Writer {
+            store: self,
+            base,
+            overlay: HashMap::new(),
+        }
+    }
+
+    fn commit(&self, overlay: HashMap<String, Option<String>>) {
+        let mut snapshot = self.inner.write().unwrap();
+        let mut next = (*snapshot.data).clone();
+        for (key, value) in overlay {
+            match value {
+                Some(value) => {
+                    next.insert(key, value);
+                }
+                None => {
+                    next.remove(&key);
+                }
+            }
+        }
+        snapshot.data = Arc::new(next);
+        snapshot.version += 1;
+    }
+}
+
+impl Default for TransactionalStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A consistent, read-only snapshot of a [`TransactionalStore`] at the
+/// moment [`TransactionalStore::begin_read`] was called.
+pub struct Reader {
+    version: u64,
+    data: Arc<HashMap<String, String>>,
+}
+
+impl Reader {
+    /// Retrieves a value from the frozen snapshot.
+    pub fn get(&self, key: &str) -> Option<&String> {
+        self.data.get(key)
+    }
+
+    /// Returns the store version this snapshot was taken at.
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+}
+
+/// A write transaction against a [`TransactionalStore`].
+///
+/// Mutations are staged in a local overlay (`None` marks a tombstone) and
+/// only become visible to other transactions once [`Writer::commit`] is
+/// called.
+pub struct Writer<'s> {
+    store: &'s TransactionalStore,
+    base: Arc<HashMap<String, String>>,
+    overlay: HashMap<String, Option<String>>,
+}
+
+impl<'s> Writer<'s> {
+    /// Stages a key-value pair to be written on commit.
+    pub fn set(&mut self, key: String, value: String) {
+        self.overlay.insert(key, Some(value));
+    }
+
+    /// Stages a key to be removed on commit.
+    pub fn delete(&mut self, key: &str) {
+        self.overlay.insert(key.to_string(), None);
+    }
+
+    /// Reads through the overlay to the base snapshot, so a writer sees its
+    /// own uncommitted staged changes.
+    pub fn get(&self, key: &str) -> Option<&String> {
+        match self.overlay.get(key) {
+            Some(Some(value)) => Some(value),
+            Some(None) => None,
+            None => self.base.get(key),
+        }
+    }
+
+    /// Applies the staged overlay to the store atomically and bumps the
+    /// store's version.
+    pub fn commit(self) {
+        self.store.commit(self.overlay);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reader_sees_snapshot_taken_at_begin_read() {
+        let store = TransactionalStore::new();
+        {
+            let mut writer = store.begin_write();
+            writer.set("name".to_string(), "Alice".to_string());
+            writer.commit();
+        }
+
+        let reader = store.begin_read();
+        assert_eq!(reader.get("name"), Some(&"Alice".to_string()));
+        assert_eq!(reader.version(), 1);
+
+        let mut writer = store.begin_write();
+        writer.set("name".to_string(), "Bob".to_string());
+        writer.commit();
+
+        // The reader opened before the second commit must not observe it.
+        assert_eq!(reader.get("name"), Some(&"Alice".to_string()));
+
+        let fresh_reader = store.begin_read();
+        assert_eq!(fresh_reader.get("name"), Some(&"Bob".to_string()));
+        assert_eq!(fresh_reader.version(), 2);
+    }
+
+    #[test]
+    fn writer_overlay_is_visible_to_itself_before_commit() {
+        let store = TransactionalStore::new();
+        let mut writer = store.begin_write();
+        writer.set("city".to_string(), "Seattle".to_string());
+        assert_eq!(writer.get("city"), Some(&"Seattle".to_string()));
+
+        let reader = store.begin_read();
+        assert_eq!(reader.get("city"), None);
+
+        writer.commit();
+        assert_eq!(store.begin_read().get("city"), Some(&"Seattle".to_string()));
+    }
+
+    #[test]
+    fn dropped_writer_discards_its_overlay() {
+        let store = TransactionalStore::new();
+        {
+            let mut writer = store.begin_write();
+            writer.set("temp".to_string(), "value".to_string());
+            // Dropped without calling commit().
+        }
+
+        assert_eq!(store.begin_read().get("temp"), None);
+    }
+
+    #[test]
+    fn writer_delete_stages_a_tombstone() {
+        let store = TransactionalStore::new();
+        let mut writer = store.begin_write();
+        writer.set("k".to_string(), "v".to_string());
+        writer.commit();
+
+        let mut writer = store.begin_write();
+        writer.delete("k");
+        assert_eq!(writer.get("k"), None);
+        writer.commit();
+
+        assert_eq!(store.begin_read().get("k"), None);
+    }
+}